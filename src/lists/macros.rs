@@ -1,39 +1,24 @@
 #[macro_export]
 macro_rules! linked_list {
-    () => (
-        UnsafeLinkedList::new()
-    );
+    () => {
+        ::std::iter::FromIterator::from_iter(::std::iter::empty())
+    };
     ($($x:expr),+ $(,)?) => {
-        {
-            let mut list = UnsafeLinkedList::new();
-
-            $(
-                list.push_front($x);
-            )*
-
-            list
-        }
+        ::std::iter::FromIterator::from_iter([$($x),+])
+    };
+    ($elem:expr; $n:expr) => {
+        ::std::iter::FromIterator::from_iter((0..$n).map(|_| $elem))
     };
-    ($elem:expr; $n:expr) => (
-        {
-            let mut list = UnsafeLinkedList::new();
-
-            for _ in 0..$n {
-                list.push_front($elem);
-            }
-
-            list
-        }
-    );
 }
 
 #[cfg(test)]
 mod test {
+    use super::super::safe_linked_list::SafeLinkedList;
     use super::super::unsafe_linked_list::UnsafeLinkedList;
 
     #[test]
     fn empty_linked_list() {
-        let mut list = linked_list![];
+        let mut list: UnsafeLinkedList<i32> = linked_list![];
 
         assert!(list.is_empty());
         assert_eq!(list.pop_front(), None);
@@ -45,7 +30,7 @@ mod test {
 
     #[test]
     fn single_linked_list() {
-        let mut list = linked_list![0];
+        let mut list: UnsafeLinkedList<i32> = linked_list![0];
 
         assert_eq!(list.len(), 1);
         assert_eq!(list.pop_front(), Some(0));
@@ -54,7 +39,7 @@ mod test {
 
     #[test]
     fn multiple_linked_list() {
-        let mut list = linked_list![0, 1, 2,];
+        let mut list: UnsafeLinkedList<i32> = linked_list![0, 1, 2,];
 
         assert_eq!(list.len(), 3);
         assert_eq!(list.pop_front(), Some(2));
@@ -65,7 +50,38 @@ mod test {
 
     #[test]
     fn repeating_value() {
-        let list = linked_list![10; 100];
+        let list: UnsafeLinkedList<i32> = linked_list![10; 100];
+
+        assert_eq!(list.len(), 100);
+        assert_eq!(list.into_iter().sum::<i32>(), 1000);
+    }
+
+    #[test]
+    fn empty_safe_linked_list() {
+        let mut list: SafeLinkedList<i32> = linked_list![];
+
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop_front(), None);
+
+        list.push_front(5);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.pop_front(), Some(5));
+    }
+
+    #[test]
+    fn multiple_safe_linked_list() {
+        let mut list: SafeLinkedList<i32> = linked_list![0, 1, 2,];
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn repeating_value_safe_linked_list() {
+        let list: SafeLinkedList<i32> = linked_list![10; 100];
 
         assert_eq!(list.len(), 100);
         assert_eq!(list.into_iter().sum::<i32>(), 1000);
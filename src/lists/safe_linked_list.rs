@@ -2,6 +2,8 @@
 
 use std::cell::{Ref, RefCell, RefMut};
 use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
 use std::rc::{Rc, Weak};
 
 type Link<T> = Option<Rc<RefCell<Node<T>>>>;
@@ -19,10 +21,24 @@ pub struct SafeLinkedList<T> {
     len: usize,
 }
 
-pub struct Iter<T> {
+pub struct IntoIter<T> {
     list: SafeLinkedList<T>,
 }
 
+pub struct Iter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    marker: PhantomData<&'a T>,
+}
+
+pub struct IterMut<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
 impl<T> Node<T> {
     fn new(data: T) -> Rc<RefCell<Self>> {
         Rc::new(RefCell::new(Node {
@@ -120,6 +136,79 @@ impl<T> SafeLinkedList<T> {
         })
     }
 
+    /// Splits off the elements from `at` onward into a new list, using the
+    /// same head-as-index-0 convention the commented-out `nth` sketch below
+    /// assumes.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "index out of bounds");
+
+        if at == 0 {
+            return mem::replace(self, Self::new());
+        }
+
+        if at == self.len {
+            return Self::new();
+        }
+
+        let split_node = if at < self.len / 2 {
+            let mut node = self.head.clone().unwrap();
+
+            for _ in 0..at {
+                let next = node.borrow().next.clone().unwrap();
+                node = next;
+            }
+
+            node
+        } else {
+            let mut node = self.tail.clone().unwrap();
+
+            for _ in 0..(self.len - at - 1) {
+                let prev = node.borrow().prev.clone().unwrap().upgrade().unwrap();
+                node = prev;
+            }
+
+            node
+        };
+
+        let prev = split_node
+            .borrow_mut()
+            .prev
+            .take()
+            .unwrap()
+            .upgrade()
+            .unwrap();
+        prev.borrow_mut().next = None;
+
+        let split = SafeLinkedList {
+            head: Some(split_node),
+            tail: self.tail.take(),
+            len: self.len - at,
+        };
+
+        self.tail = Some(prev);
+        self.len = at;
+
+        split
+    }
+
+    /// Appends `other` onto `self` in O(1), leaving `other` empty;
+    /// re-downgrades the boundary's `prev` side to a `Weak`.
+    pub fn append(&mut self, other: &mut Self) {
+        match self.tail.take() {
+            None => mem::swap(self, other),
+            Some(tail) => match other.head.take() {
+                Some(other_head) => {
+                    other_head.borrow_mut().prev = Some(Rc::downgrade(&tail));
+                    tail.borrow_mut().next = Some(other_head);
+
+                    self.tail = other.tail.take();
+                    self.len += mem::take(&mut other.len);
+                }
+                None => self.tail = Some(tail),
+            },
+        }
+    }
+
     pub fn peek_front(&self) -> Option<Ref<T>> {
         self.tail
             .as_ref()
@@ -174,17 +263,101 @@ impl<T> SafeLinkedList<T> {
     //     }
     // }
 
+    // `next`/`next_back` hand out `&T` derived from a momentarily-borrowed
+    // `Ref` without keeping that `Ref` alive, which would let a caller hold
+    // a live `&T` while `peek_front_mut`/`peek_back_mut` (also `&self`,
+    // via `RefCell`) hand out a `&mut T` into the same node. Requiring
+    // `&mut self` here, like `iter_mut` already does, makes the returned
+    // `Iter`'s lifetime borrow the whole list exclusively, so the borrow
+    // checker rules out any such overlap at compile time instead of
+    // relying on `RefCell`'s runtime check (which the raw-pointer hand-off
+    // below bypasses).
+    pub fn iter(&mut self) -> Iter<'_, T> {
+        Iter {
+            head: self.head.clone(),
+            tail: self.tail.clone(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head.clone(),
+            tail: self.tail.clone(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.len
     }
+
+    /// Checks that `head`/`tail`/`len` and every node's `next`/`prev` (the
+    /// latter upgraded from `Weak` and compared by `Rc::ptr_eq`) are
+    /// mutually consistent.
+    #[cfg(test)]
+    pub fn debug_assert_links(&self) {
+        assert_eq!(self.head.is_none(), self.len == 0);
+        assert_eq!(self.tail.is_none(), self.len == 0);
+
+        if let Some(head) = &self.head {
+            assert!(head.borrow().prev.is_none());
+        }
+
+        if let Some(tail) = &self.tail {
+            assert!(tail.borrow().next.is_none());
+        }
+
+        let mut count = 0;
+        let mut node = self.head.clone();
+
+        while let Some(n) = node {
+            let next = n.borrow().next.clone();
+
+            if let Some(next) = &next {
+                let next_prev = next.borrow().prev.clone().and_then(|prev| prev.upgrade());
+                assert!(next_prev.is_some_and(|prev| Rc::ptr_eq(&prev, &n)));
+            }
+
+            let prev = n.borrow().prev.clone().and_then(|prev| prev.upgrade());
+            if let Some(prev) = prev {
+                let prev_next = prev.borrow().next.clone();
+                assert!(prev_next.is_some_and(|next| Rc::ptr_eq(&next, &n)));
+            }
+
+            count += 1;
+            node = next;
+        }
+
+        assert_eq!(count, self.len);
+    }
 }
 
 impl<T> IntoIterator for SafeLinkedList<T> {
     type Item = T;
-    type IntoIter = Iter<T>;
+    type IntoIter = IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Iter { list: self }
+        IntoIter { list: self }
+    }
+}
+
+impl<T> FromIterator<T> for SafeLinkedList<T> {
+    // push_front, not push_back, is what preserves the source order here.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SafeLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for SafeLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_front(value);
+        }
     }
 }
 
@@ -206,7 +379,7 @@ impl<T: fmt::Display> fmt::Display for SafeLinkedList<T> {
     }
 }
 
-impl<T> Iterator for Iter<T> {
+impl<T> Iterator for IntoIter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
@@ -214,6 +387,80 @@ impl<T> Iterator for Iter<T> {
     }
 }
 
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.take().map(|node| {
+            self.len -= 1;
+            self.head = node.borrow().next.clone();
+
+            let data = &node.borrow().data as *const T;
+            unsafe { &*data }
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.take().map(|node| {
+            self.len -= 1;
+            self.tail = node.borrow().prev.clone().and_then(|prev| prev.upgrade());
+
+            let data = &node.borrow().data as *const T;
+            unsafe { &*data }
+        })
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.take().map(|node| {
+            self.len -= 1;
+            self.head = node.borrow().next.clone();
+
+            let data = &mut node.borrow_mut().data as *mut T;
+            unsafe { &mut *data }
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.take().map(|node| {
+            self.len -= 1;
+            self.tail = node.borrow().prev.clone().and_then(|prev| prev.upgrade());
+
+            let data = &mut node.borrow_mut().data as *mut T;
+            unsafe { &mut *data }
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -223,8 +470,11 @@ mod test {
         let mut list = SafeLinkedList::new();
 
         list.push_front(0);
+        list.debug_assert_links();
         list.push_front(1);
+        list.debug_assert_links();
         list.push_front(2);
+        list.debug_assert_links();
 
         assert_eq!(format!("{}", list), "[0, 1, 2]");
     }
@@ -234,8 +484,11 @@ mod test {
         let mut list = SafeLinkedList::new();
 
         list.push_back(0);
+        list.debug_assert_links();
         list.push_back(1);
+        list.debug_assert_links();
         list.push_back(2);
+        list.debug_assert_links();
 
         assert_eq!(format!("{}", list), "[2, 1, 0]");
     }
@@ -247,11 +500,16 @@ mod test {
         list.push_front(0);
         list.push_front(1);
         list.push_front(2);
+        list.debug_assert_links();
 
         assert_eq!(list.pop_front(), Some(2));
+        list.debug_assert_links();
         assert_eq!(list.pop_front(), Some(1));
+        list.debug_assert_links();
         assert_eq!(list.pop_front(), Some(0));
+        list.debug_assert_links();
         assert_eq!(list.pop_front(), None);
+        list.debug_assert_links();
     }
 
     #[test]
@@ -261,11 +519,16 @@ mod test {
         list.push_back(0);
         list.push_back(1);
         list.push_back(2);
+        list.debug_assert_links();
 
         assert_eq!(list.pop_back(), Some(2));
+        list.debug_assert_links();
         assert_eq!(list.pop_back(), Some(1));
+        list.debug_assert_links();
         assert_eq!(list.pop_back(), Some(0));
+        list.debug_assert_links();
         assert_eq!(list.pop_back(), None);
+        list.debug_assert_links();
     }
 
     #[test]
@@ -275,12 +538,15 @@ mod test {
         assert!(list.peek_front().is_none());
 
         list.push_front(0);
+        list.debug_assert_links();
         assert_eq!(&*list.peek_front().unwrap(), &0);
 
         list.push_front(1);
+        list.debug_assert_links();
         assert_eq!(&*list.peek_front().unwrap(), &1);
 
         list.push_front(2);
+        list.debug_assert_links();
         assert_eq!(&*list.peek_front().unwrap(), &2);
     }
 
@@ -291,12 +557,15 @@ mod test {
         assert!(list.peek_back().is_none());
 
         list.push_back(0);
+        list.debug_assert_links();
         assert_eq!(&*list.peek_back().unwrap(), &0);
 
         list.push_back(1);
+        list.debug_assert_links();
         assert_eq!(&*list.peek_back().unwrap(), &1);
 
         list.push_back(2);
+        list.debug_assert_links();
         assert_eq!(&*list.peek_back().unwrap(), &2);
     }
 
@@ -307,12 +576,15 @@ mod test {
         assert!(list.peek_front().is_none());
 
         list.push_front(0);
+        list.debug_assert_links();
         assert_eq!(&mut *list.peek_front_mut().unwrap(), &mut 0);
 
         list.push_front(1);
+        list.debug_assert_links();
         assert_eq!(&mut *list.peek_front_mut().unwrap(), &mut 1);
 
         list.push_front(2);
+        list.debug_assert_links();
         assert_eq!(&mut *list.peek_front_mut().unwrap(), &mut 2);
     }
 
@@ -323,12 +595,15 @@ mod test {
         assert!(list.peek_back().is_none());
 
         list.push_back(0);
+        list.debug_assert_links();
         assert_eq!(&mut *list.peek_back_mut().unwrap(), &mut 0);
 
         list.push_back(1);
+        list.debug_assert_links();
         assert_eq!(&mut *list.peek_back_mut().unwrap(), &mut 1);
 
         list.push_back(2);
+        list.debug_assert_links();
         assert_eq!(&mut *list.peek_back_mut().unwrap(), &mut 2);
     }
 
@@ -342,4 +617,147 @@ mod test {
 
         assert_eq!(list.into_iter().sum::<i32>(), 45);
     }
+
+    #[test]
+    fn iter() {
+        let mut list = SafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let mut list = SafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let values: Vec<&i32> = list.iter().rev().collect();
+        assert_eq!(values, vec![&2, &1, &0]);
+    }
+
+    #[test]
+    fn iter_meets_in_the_middle() {
+        let mut list = SafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = SafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        for value in list.iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(format!("{}", list), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list: SafeLinkedList<i32> = (0..5).collect();
+
+        let mut split = list.split_off(2);
+        list.debug_assert_links();
+        split.debug_assert_links();
+
+        assert_eq!(format!("{}", list), "[0, 1]");
+        assert_eq!(format!("{}", split), "[2, 3, 4]");
+        assert_eq!(list.len(), 2);
+        assert_eq!(split.len(), 3);
+
+        list.append(&mut split);
+        list.debug_assert_links();
+        split.debug_assert_links();
+
+        assert_eq!(format!("{}", list), "[0, 1, 2, 3, 4]");
+        assert_eq!(list.len(), 5);
+        assert_eq!(split.len(), 0);
+    }
+
+    #[test]
+    fn split_off_at_ends() {
+        let mut list: SafeLinkedList<i32> = (0..3).collect();
+
+        let empty = list.split_off(3);
+        list.debug_assert_links();
+        assert_eq!(empty.len(), 0);
+        assert_eq!(list.len(), 3);
+
+        let all = list.split_off(0);
+        list.debug_assert_links();
+        all.debug_assert_links();
+        assert_eq!(list.len(), 0);
+        assert_eq!(format!("{}", all), "[0, 1, 2]");
+    }
+
+    #[test]
+    fn append() {
+        let mut a: SafeLinkedList<i32> = (0..3).collect();
+        let mut b: SafeLinkedList<i32> = (3..6).collect();
+
+        a.append(&mut b);
+        a.debug_assert_links();
+        b.debug_assert_links();
+
+        assert_eq!(format!("{}", a), "[0, 1, 2, 3, 4, 5]");
+        assert_eq!(a.len(), 6);
+        assert_eq!(b.len(), 0);
+    }
+
+    #[test]
+    fn append_to_empty() {
+        let mut a: SafeLinkedList<i32> = SafeLinkedList::new();
+        let mut b: SafeLinkedList<i32> = (0..3).collect();
+
+        a.append(&mut b);
+        a.debug_assert_links();
+
+        assert_eq!(format!("{}", a), "[0, 1, 2]");
+        assert_eq!(b.len(), 0);
+    }
+
+    #[test]
+    fn from_iter() {
+        let list: SafeLinkedList<i32> = (0..5).collect();
+        list.debug_assert_links();
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(format!("{}", list), "[0, 1, 2, 3, 4]");
+    }
+
+    #[test]
+    fn extend() {
+        let mut list: SafeLinkedList<i32> = (0..3).collect();
+        list.extend(3..5);
+        list.debug_assert_links();
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(format!("{}", list), "[0, 1, 2, 3, 4]");
+    }
 }
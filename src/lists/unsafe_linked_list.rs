@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 
 use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
 use std::ptr::NonNull;
 
 #[derive(Debug)]
@@ -16,10 +18,36 @@ pub struct UnsafeLinkedList<T> {
     len: usize,
 }
 
-pub struct Iter<T> {
+pub struct IntoIter<T> {
     list: UnsafeLinkedList<T>,
 }
 
+pub struct Iter<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a T>,
+}
+
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+pub struct Cursor<'a, T> {
+    list: &'a UnsafeLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    index: Option<usize>,
+}
+
+pub struct CursorMut<'a, T> {
+    list: &'a mut UnsafeLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    index: Option<usize>,
+}
+
 impl<T> Node<T> {
     fn new(data: T) -> Self {
         Node {
@@ -74,6 +102,11 @@ impl<T> UnsafeLinkedList<T> {
             let tail = unsafe { Box::from_raw(tail.as_ptr()) };
             self.tail = tail.prev;
 
+            match self.tail {
+                Some(tail) => unsafe { (*tail.as_ptr()).next = None },
+                None => self.head = None,
+            }
+
             self.len -= 1;
             tail.data
         })
@@ -84,32 +117,121 @@ impl<T> UnsafeLinkedList<T> {
             let head = unsafe { Box::from_raw(head.as_ptr()) };
             self.head = head.next;
 
+            match self.head {
+                Some(head) => unsafe { (*head.as_ptr()).prev = None },
+                None => self.tail = None,
+            }
+
             self.len -= 1;
             head.data
         })
     }
 
-    pub fn nth(&self, index: usize) -> Option<T> {
+    pub fn nth(&mut self, index: usize) -> Option<T> {
         if index >= self.len() {
-            None
-        } else if index < self.len() / 2 {
-            let mut node = self.head;
+            return None;
+        }
+
+        let node = if index < self.len() / 2 {
+            let mut node = self.head.unwrap();
 
             for _ in 0..index {
-                node = unsafe { node.unwrap().as_ref().next };
+                node = unsafe { node.as_ref().next.unwrap() };
             }
 
-            let node = unsafe { Box::from_raw(node.unwrap().as_ptr()) };
-            Some(node.data)
+            node
         } else {
-            let mut node = self.tail;
+            let mut node = self.tail.unwrap();
 
             for _ in 0..(self.len() - index - 1) {
-                node = unsafe { node.unwrap().as_ref().prev };
+                node = unsafe { node.as_ref().prev.unwrap() };
             }
 
-            let node = unsafe { Box::from_raw(node.unwrap().as_ptr()) };
-            Some(node.data)
+            node
+        };
+
+        // Unlink the node from its neighbors (or head/tail) before freeing
+        // it, instead of leaving the list pointing at freed memory.
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+
+        match node.prev {
+            Some(prev) => unsafe { (*prev.as_ptr()).next = node.next },
+            None => self.head = node.next,
+        }
+
+        match node.next {
+            Some(next) => unsafe { (*next.as_ptr()).prev = node.prev },
+            None => self.tail = node.prev,
+        }
+
+        self.len -= 1;
+        Some(node.data)
+    }
+
+    /// Splits off the elements from `at` onward into a new list, using the
+    /// same head-as-index-0 convention as [`nth`](Self::nth).
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "index out of bounds");
+
+        if at == 0 {
+            return mem::replace(self, Self::new());
+        }
+
+        if at == self.len {
+            return Self::new();
+        }
+
+        let split_node = if at < self.len / 2 {
+            let mut node = self.head.unwrap();
+
+            for _ in 0..at {
+                node = unsafe { node.as_ref().next.unwrap() };
+            }
+
+            node
+        } else {
+            let mut node = self.tail.unwrap();
+
+            for _ in 0..(self.len - at - 1) {
+                node = unsafe { node.as_ref().prev.unwrap() };
+            }
+
+            node
+        };
+
+        let prev = unsafe { split_node.as_ref().prev.unwrap() };
+        unsafe {
+            (*prev.as_ptr()).next = None;
+            (*split_node.as_ptr()).prev = None;
+        }
+
+        let split = UnsafeLinkedList {
+            head: Some(split_node),
+            tail: self.tail,
+            len: self.len - at,
+        };
+
+        self.tail = Some(prev);
+        self.len = at;
+
+        split
+    }
+
+    /// Appends `other` onto `self` in O(1), leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        match self.tail {
+            None => mem::swap(self, other),
+            Some(tail) => {
+                if let Some(other_head) = other.head.take() {
+                    unsafe {
+                        (*tail.as_ptr()).next = Some(other_head);
+                        (*other_head.as_ptr()).prev = Some(tail);
+                    }
+
+                    self.tail = other.tail.take();
+                    self.len += mem::take(&mut other.len);
+                }
+            }
         }
     }
 
@@ -137,8 +259,64 @@ impl<T> UnsafeLinkedList<T> {
             .map(|node| unsafe { &mut node.as_mut().data })
     }
 
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.head,
+            index: self.head.map(|_| 0),
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.tail,
+            index: self.tail.map(|_| self.len - 1),
+            list: self,
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.head;
+        let index = current.map(|_| 0);
+
+        CursorMut {
+            current,
+            index,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.tail;
+        let index = current.map(|_| self.len - 1);
+
+        CursorMut {
+            current,
+            index,
+            list: self,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
     pub fn clear(&mut self) {
-        *self = Self::new();
+        while self.pop_back().is_some() {}
     }
 
     pub fn is_empty(&self) -> bool {
@@ -148,14 +326,73 @@ impl<T> UnsafeLinkedList<T> {
     pub fn len(&self) -> usize {
         self.len
     }
+
+    /// Checks that `head`/`tail`/`len` and every node's `next`/`prev` are
+    /// mutually consistent.
+    #[cfg(test)]
+    pub fn debug_assert_links(&self) {
+        assert_eq!(self.head.is_none(), self.len == 0);
+        assert_eq!(self.tail.is_none(), self.len == 0);
+
+        if let Some(head) = self.head {
+            assert!(unsafe { head.as_ref().prev.is_none() });
+        }
+
+        if let Some(tail) = self.tail {
+            assert!(unsafe { tail.as_ref().next.is_none() });
+        }
+
+        let mut count = 0;
+        let mut node = self.head;
+
+        while let Some(n) = node {
+            let next = unsafe { n.as_ref().next };
+
+            if let Some(next) = next {
+                assert_eq!(unsafe { next.as_ref().prev }, Some(n));
+            }
+
+            if let Some(prev) = unsafe { n.as_ref().prev } {
+                assert_eq!(unsafe { prev.as_ref().next }, Some(n));
+            }
+
+            count += 1;
+            node = next;
+        }
+
+        assert_eq!(count, self.len);
+    }
+}
+
+impl<T> Drop for UnsafeLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
 }
 
 impl<T> IntoIterator for UnsafeLinkedList<T> {
     type Item = T;
-    type IntoIter = Iter<T>;
+    type IntoIter = IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Iter { list: self }
+        IntoIter { list: self }
+    }
+}
+
+impl<T> FromIterator<T> for UnsafeLinkedList<T> {
+    // push_front, not push_back, is what preserves the source order here.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = UnsafeLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for UnsafeLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_front(value);
+        }
     }
 }
 
@@ -179,7 +416,7 @@ impl<T: fmt::Display + fmt::Debug> fmt::Display for UnsafeLinkedList<T> {
     }
 }
 
-impl<T> Iterator for Iter<T> {
+impl<T> Iterator for IntoIter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
@@ -187,17 +424,309 @@ impl<T> Iterator for Iter<T> {
     }
 }
 
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|node| unsafe {
+            self.len -= 1;
+            self.head = node.as_ref().next;
+            &node.as_ref().data
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|node| unsafe {
+            self.len -= 1;
+            self.tail = node.as_ref().prev;
+            &node.as_ref().data
+        })
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|mut node| unsafe {
+            self.len -= 1;
+            self.head = node.as_ref().next;
+            &mut node.as_mut().data
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|mut node| unsafe {
+            self.len -= 1;
+            self.tail = node.as_ref().prev;
+            &mut node.as_mut().data
+        })
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Moves the cursor towards the tail, wrapping onto the ghost
+    /// (non-element) position after the last node and back to the head
+    /// afterwards, so the cursor never gets stuck.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().next };
+                self.index = self.current.map(|_| self.index.unwrap() + 1);
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = self.current.map(|_| 0);
+            }
+        }
+    }
+
+    /// Moves the cursor towards the head, wrapping onto the ghost
+    /// position before the first node and back to the tail afterwards.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().prev };
+                self.index = self.current.map(|_| self.index.unwrap() - 1);
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.current.map(|_| self.list.len - 1);
+            }
+        }
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        self.current.map(|node| unsafe { &node.as_ref().data })
+    }
+
+    pub fn peek_next(&self) -> Option<&T> {
+        let next = match self.current {
+            Some(node) => unsafe { node.as_ref().next },
+            None => self.list.head,
+        };
+
+        next.map(|node| unsafe { &node.as_ref().data })
+    }
+
+    pub fn peek_prev(&self) -> Option<&T> {
+        let prev = match self.current {
+            Some(node) => unsafe { node.as_ref().prev },
+            None => self.list.tail,
+        };
+
+        prev.map(|node| unsafe { &node.as_ref().data })
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().next };
+                self.index = self.current.map(|_| self.index.unwrap() + 1);
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = self.current.map(|_| 0);
+            }
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { node.as_ref().prev };
+                self.index = self.current.map(|_| self.index.unwrap() - 1);
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.current.map(|_| self.list.len - 1);
+            }
+        }
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|mut node| unsafe { &mut node.as_mut().data })
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            Some(node) => unsafe { node.as_ref().next },
+            None => self.list.head,
+        };
+
+        next.map(|mut node| unsafe { &mut node.as_mut().data })
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.current {
+            Some(node) => unsafe { node.as_ref().prev },
+            None => self.list.tail,
+        };
+
+        prev.map(|mut node| unsafe { &mut node.as_mut().data })
+    }
+
+    /// Splices a new node in just before the current one. If the cursor is
+    /// on the ghost position, the new node becomes the list's tail, since
+    /// the ghost sits right after the tail in traversal order.
+    pub fn insert_before(&mut self, value: T) {
+        let mut node = Node::new(value);
+
+        match self.current {
+            Some(current) => {
+                let prev = unsafe { current.as_ref().prev };
+                node.prev = prev;
+                node.next = Some(current);
+                let node = Some(Box::leak(Box::new(node)).into());
+
+                match prev {
+                    Some(prev) => unsafe { (*prev.as_ptr()).next = node },
+                    None => self.list.head = node,
+                }
+                unsafe { (*current.as_ptr()).prev = node };
+
+                self.list.len += 1;
+                self.index = self.index.map(|index| index + 1);
+            }
+            None => {
+                node.prev = self.list.tail;
+                let node = Some(Box::leak(Box::new(node)).into());
+
+                match self.list.tail {
+                    Some(tail) => unsafe { (*tail.as_ptr()).next = node },
+                    None => self.list.head = node,
+                }
+                self.list.tail = node;
+
+                self.list.len += 1;
+            }
+        }
+    }
+
+    /// Splices a new node in just after the current one. If the cursor is
+    /// on the ghost position, the new node becomes the list's head, since
+    /// the ghost sits right before the head in traversal order.
+    pub fn insert_after(&mut self, value: T) {
+        let mut node = Node::new(value);
+
+        match self.current {
+            Some(current) => {
+                let next = unsafe { current.as_ref().next };
+                node.next = next;
+                node.prev = Some(current);
+                let node = Some(Box::leak(Box::new(node)).into());
+
+                match next {
+                    Some(next) => unsafe { (*next.as_ptr()).prev = node },
+                    None => self.list.tail = node,
+                }
+                unsafe { (*current.as_ptr()).next = node };
+
+                self.list.len += 1;
+            }
+            None => {
+                node.next = self.list.head;
+                let node = Some(Box::leak(Box::new(node)).into());
+
+                match self.list.head {
+                    Some(head) => unsafe { (*head.as_ptr()).prev = node },
+                    None => self.list.tail = node,
+                }
+                self.list.head = node;
+
+                self.list.len += 1;
+            }
+        }
+    }
+
+    /// Unlinks the current node and returns its data, advancing the cursor
+    /// onto the node that follows (or the ghost position, if it was the
+    /// tail).
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current.take()?;
+        let node = unsafe { Box::from_raw(current.as_ptr()) };
+
+        match node.prev {
+            Some(prev) => unsafe { (*prev.as_ptr()).next = node.next },
+            None => self.list.head = node.next,
+        }
+
+        match node.next {
+            Some(next) => unsafe { (*next.as_ptr()).prev = node.prev },
+            None => self.list.tail = node.prev,
+        }
+
+        self.list.len -= 1;
+        self.current = node.next;
+
+        if self.current.is_none() {
+            self.index = None;
+        }
+
+        Some(node.data)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
 
     #[test]
     fn push_front() {
         let mut list = UnsafeLinkedList::new();
 
         list.push_front(0);
+        list.debug_assert_links();
         list.push_front(1);
+        list.debug_assert_links();
         list.push_front(2);
+        list.debug_assert_links();
 
         assert_eq!(format!("{}", list), "[0, 1, 2]");
     }
@@ -207,8 +736,11 @@ mod test {
         let mut list = UnsafeLinkedList::new();
 
         list.push_back(0);
+        list.debug_assert_links();
         list.push_back(1);
+        list.debug_assert_links();
         list.push_back(2);
+        list.debug_assert_links();
 
         assert_eq!(format!("{}", list), "[2, 1, 0]");
     }
@@ -220,11 +752,16 @@ mod test {
         list.push_front(0);
         list.push_front(1);
         list.push_front(2);
+        list.debug_assert_links();
 
         assert_eq!(list.pop_front(), Some(2));
+        list.debug_assert_links();
         assert_eq!(list.pop_front(), Some(1));
+        list.debug_assert_links();
         assert_eq!(list.pop_front(), Some(0));
+        list.debug_assert_links();
         assert_eq!(list.pop_front(), None);
+        list.debug_assert_links();
     }
 
     #[test]
@@ -234,11 +771,16 @@ mod test {
         list.push_back(0);
         list.push_back(1);
         list.push_back(2);
+        list.debug_assert_links();
 
         assert_eq!(list.pop_back(), Some(2));
+        list.debug_assert_links();
         assert_eq!(list.pop_back(), Some(1));
+        list.debug_assert_links();
         assert_eq!(list.pop_back(), Some(0));
+        list.debug_assert_links();
         assert_eq!(list.pop_back(), None);
+        list.debug_assert_links();
     }
 
     #[test]
@@ -248,10 +790,24 @@ mod test {
         list.push_front(0);
         list.push_front(1);
         list.push_front(2);
+        list.push_front(3);
+        list.push_front(4);
+        list.debug_assert_links();
 
-        assert_eq!(list.nth(0), Some(0));
-        assert_eq!(list.nth(1), Some(1));
         assert_eq!(list.nth(2), Some(2));
+        list.debug_assert_links();
+        assert_eq!(list.len(), 4);
+        assert_eq!(format!("{}", list), "[0, 1, 3, 4]");
+
+        assert_eq!(list.nth(0), Some(0));
+        list.debug_assert_links();
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{}", list), "[1, 3, 4]");
+
+        assert_eq!(list.nth(2), Some(4));
+        list.debug_assert_links();
+        assert_eq!(list.len(), 2);
+        assert_eq!(format!("{}", list), "[1, 3]");
     }
 
     #[test]
@@ -330,10 +886,41 @@ mod test {
         assert_eq!(format!("{}", list), "[0, 1, 2]");
 
         list.clear();
+        list.debug_assert_links();
         assert_eq!(list.len(), 0);
         assert_eq!(format!("{}", list), "[]");
     }
 
+    #[test]
+    fn clear_drops_all_elements() {
+        let counter = Rc::new(Cell::new(0));
+        let mut list = UnsafeLinkedList::new();
+
+        for _ in 0..3 {
+            list.push_front(DropCounter(counter.clone()));
+        }
+
+        assert_eq!(counter.get(), 0);
+
+        list.clear();
+        list.debug_assert_links();
+        assert_eq!(counter.get(), 3);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn drop_drops_all_elements() {
+        let counter = Rc::new(Cell::new(0));
+        let mut list = UnsafeLinkedList::new();
+
+        for _ in 0..3 {
+            list.push_front(DropCounter(counter.clone()));
+        }
+
+        drop(list);
+        assert_eq!(counter.get(), 3);
+    }
+
     #[test]
     fn is_empty() {
         let mut list = UnsafeLinkedList::new();
@@ -341,15 +928,19 @@ mod test {
         assert!(list.is_empty());
 
         list.push_front(0);
+        list.debug_assert_links();
         assert!(!list.is_empty());
 
         list.push_front(1);
+        list.debug_assert_links();
         assert!(!list.is_empty());
 
         list.push_front(2);
+        list.debug_assert_links();
         assert!(!list.is_empty());
 
         list.clear();
+        list.debug_assert_links();
         assert!(list.is_empty());
     }
 
@@ -360,18 +951,23 @@ mod test {
         assert_eq!(list.len(), 0);
 
         list.push_front(0);
+        list.debug_assert_links();
         assert_eq!(list.len(), 1);
 
         list.push_front(1);
+        list.debug_assert_links();
         assert_eq!(list.len(), 2);
 
         list.pop_front();
+        list.debug_assert_links();
         assert_eq!(list.len(), 1);
 
         list.push_front(2);
+        list.debug_assert_links();
         assert_eq!(list.len(), 2);
 
         list.clear();
+        list.debug_assert_links();
         assert_eq!(list.len(), 0);
     }
 
@@ -385,4 +981,268 @@ mod test {
 
         assert_eq!(list.into_iter().sum::<i32>(), 45);
     }
+
+    #[test]
+    fn iter() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let values: Vec<&i32> = list.iter().rev().collect();
+        assert_eq!(values, vec![&2, &1, &0]);
+    }
+
+    #[test]
+    fn iter_meets_in_the_middle() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        for value in list.iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(format!("{}", list), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn cursor_move_next_wraps_through_ghost() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&0));
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.current(), Some(&1));
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&2));
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.current(), None);
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&0));
+    }
+
+    #[test]
+    fn cursor_move_prev_wraps_through_ghost() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let mut cursor = list.cursor_back();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&2));
+
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.current(), Some(&1));
+
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&0));
+
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.current(), None);
+
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&2));
+    }
+
+    #[test]
+    fn cursor_peek_next_and_prev() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+        list.debug_assert_links();
+
+        let cursor = list.cursor_front();
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(cursor.peek_next(), Some(&1));
+    }
+
+    #[test]
+    fn cursor_mut_insert_before_and_after() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(0);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.insert_before(1);
+        cursor.insert_after(3);
+
+        assert_eq!(format!("{}", list), "[0, 1, 2, 3]");
+        assert_eq!(list.len(), 4);
+        list.debug_assert_links();
+    }
+
+    #[test]
+    fn cursor_mut_insert_on_ghost() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(1);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+
+        cursor.insert_after(0);
+        cursor.insert_before(2);
+
+        assert_eq!(format!("{}", list), "[0, 1, 2]");
+        assert_eq!(list.len(), 3);
+        list.debug_assert_links();
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list: UnsafeLinkedList<i32> = (0..5).collect();
+
+        let mut split = list.split_off(2);
+        list.debug_assert_links();
+        split.debug_assert_links();
+
+        assert_eq!(format!("{}", list), "[0, 1]");
+        assert_eq!(format!("{}", split), "[2, 3, 4]");
+        assert_eq!(list.len(), 2);
+        assert_eq!(split.len(), 3);
+
+        list.append(&mut split);
+        list.debug_assert_links();
+        split.debug_assert_links();
+
+        assert_eq!(format!("{}", list), "[0, 1, 2, 3, 4]");
+        assert_eq!(list.len(), 5);
+        assert!(split.is_empty());
+    }
+
+    #[test]
+    fn split_off_at_ends() {
+        let mut list: UnsafeLinkedList<i32> = (0..3).collect();
+
+        let empty = list.split_off(3);
+        list.debug_assert_links();
+        assert!(empty.is_empty());
+        assert_eq!(list.len(), 3);
+
+        let all = list.split_off(0);
+        list.debug_assert_links();
+        all.debug_assert_links();
+        assert!(list.is_empty());
+        assert_eq!(format!("{}", all), "[0, 1, 2]");
+    }
+
+    #[test]
+    fn append() {
+        let mut a: UnsafeLinkedList<i32> = (0..3).collect();
+        let mut b: UnsafeLinkedList<i32> = (3..6).collect();
+
+        a.append(&mut b);
+        a.debug_assert_links();
+        b.debug_assert_links();
+
+        assert_eq!(format!("{}", a), "[0, 1, 2, 3, 4, 5]");
+        assert_eq!(a.len(), 6);
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn append_to_empty() {
+        let mut a: UnsafeLinkedList<i32> = UnsafeLinkedList::new();
+        let mut b: UnsafeLinkedList<i32> = (0..3).collect();
+
+        a.append(&mut b);
+        a.debug_assert_links();
+
+        assert_eq!(format!("{}", a), "[0, 1, 2]");
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn from_iter() {
+        let list: UnsafeLinkedList<i32> = (0..5).collect();
+        list.debug_assert_links();
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(format!("{}", list), "[0, 1, 2, 3, 4]");
+    }
+
+    #[test]
+    fn extend() {
+        let mut list: UnsafeLinkedList<i32> = (0..3).collect();
+        list.extend(3..5);
+        list.debug_assert_links();
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(format!("{}", list), "[0, 1, 2, 3, 4]");
+    }
+
+    #[test]
+    fn cursor_mut_remove_current() {
+        let mut list = UnsafeLinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        list.push_back(0);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(format!("{}", list), "[0, 2]");
+        assert_eq!(list.len(), 2);
+        list.debug_assert_links();
+    }
 }